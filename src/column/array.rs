@@ -0,0 +1,64 @@
+use std::io;
+use std::sync::Arc;
+
+use column::column_data::{ColumnData, SqlType, ValueRef};
+
+use binary::ReadEx;
+use chrono_tz::Tz;
+
+/// This type represents `Array(T)` column data.
+pub struct ArrayColumnData {
+    pub inner: Arc<ColumnData + Send + Sync>,
+    pub offsets: Vec<u64>,
+}
+
+impl ArrayColumnData {
+    pub fn load<T: ReadEx>(
+        reader: &mut T,
+        inner_type: &str,
+        size: usize,
+        tz: Tz,
+    ) -> Result<Self, io::Error> {
+        let mut offsets = Vec::with_capacity(size);
+        for _ in 0..size {
+            offsets.push(reader.read_u64()?);
+        }
+
+        let total_elements = match offsets.last() {
+            Some(&last) => last as usize,
+            None => 0,
+        };
+
+        let inner = ColumnData::load_data(reader, inner_type, total_elements, tz)?;
+
+        Ok(ArrayColumnData { inner, offsets })
+    }
+
+    /// The half-open `[start, end)` range of inner-column indexes that make
+    /// up the array at `index`.
+    pub fn range_at(&self, index: usize) -> (usize, usize) {
+        let start = if index == 0 {
+            0
+        } else {
+            self.offsets[index - 1] as usize
+        };
+        let end = self.offsets[index] as usize;
+        (start, end)
+    }
+}
+
+impl ColumnData for ArrayColumnData {
+    fn sql_type(&self) -> SqlType {
+        SqlType::Array(Box::new(self.inner.sql_type()))
+    }
+
+    fn len(&self) -> usize {
+        self.offsets.len()
+    }
+
+    fn at(&self, index: usize) -> ValueRef {
+        let (start, end) = self.range_at(index);
+        let values = (start..end).map(|i| self.inner.at(i)).collect();
+        ValueRef::Array(values)
+    }
+}
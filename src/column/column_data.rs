@@ -0,0 +1,54 @@
+/// The ClickHouse type that a column holds, parsed from its wire type name.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SqlType {
+    UInt8,
+    UInt16,
+    UInt32,
+    UInt64,
+    Int8,
+    Int16,
+    Int32,
+    Int64,
+    Float32,
+    Float64,
+    String,
+    Date,
+    DateTime,
+    Nullable(Box<SqlType>),
+    Array(Box<SqlType>),
+    Enum8,
+    Enum16,
+}
+
+/// A single cell's worth of column data, borrowed out of the column buffer.
+#[derive(Debug, Clone)]
+pub enum ValueRef<'a> {
+    Null,
+    UInt8(u8),
+    UInt16(u16),
+    UInt32(u32),
+    UInt64(u64),
+    Int8(i8),
+    Int16(i16),
+    Int32(i32),
+    Int64(i64),
+    Float32(f32),
+    Float64(f64),
+    String(&'a [u8]),
+    Array(Vec<ValueRef<'a>>),
+    Enum(i16, &'a str),
+}
+
+/// Common interface implemented by every column's in-memory representation,
+/// letting the rest of the driver work with a column without knowing its
+/// concrete wire type.
+pub trait ColumnData {
+    /// The ClickHouse type this column holds.
+    fn sql_type(&self) -> SqlType;
+
+    /// Number of rows in the column.
+    fn len(&self) -> usize;
+
+    /// Borrows the value at `index`.
+    fn at(&self, index: usize) -> ValueRef;
+}
@@ -0,0 +1,239 @@
+use column::column_data::{ColumnData, SqlType, ValueRef};
+
+use binary::ReadEx;
+use errors::{Error, FromSqlError, Result};
+
+/// Column data for `Enum8(...)`.
+///
+/// Values are read off the wire exactly like a plain `Int8` vector, but the
+/// `name -> value` mapping parsed out of the type string (e.g.
+/// `Enum8('a' = 1, 'b' = 2)`) is retained so rows can be resolved to their
+/// string label.
+pub struct Enum8ColumnData {
+    pub values: Vec<i8>,
+    pub value_map: Vec<(String, i8)>,
+}
+
+impl Enum8ColumnData {
+    pub fn load<T: ReadEx>(reader: &mut T, size: usize, enum_values: &str) -> Result<Self> {
+        let mut value_map = Vec::with_capacity(16);
+        for (name, value) in parse_enum_values(enum_values)? {
+            if value < i64::from(i8::min_value()) || value > i64::from(i8::max_value()) {
+                return Err(Error::FromSql(FromSqlError::OutOfRange));
+            }
+            value_map.push((name, value as i8));
+        }
+
+        let mut values = Vec::with_capacity(size);
+        for _ in 0..size {
+            values.push(reader.read_i8()?);
+        }
+
+        for &value in &values {
+            if !value_map.iter().any(|&(_, v)| v == value) {
+                return Err(Error::FromSql(FromSqlError::OutOfRange));
+            }
+        }
+
+        Ok(Enum8ColumnData { values, value_map })
+    }
+
+    /// The raw integer code stored for the row at `index`.
+    pub fn value_at(&self, index: usize) -> i8 {
+        self.values[index]
+    }
+
+    /// The string label the row at `index` resolves to.
+    pub fn name_at(&self, index: usize) -> &str {
+        let value = self.values[index];
+        self.value_map
+            .iter()
+            .find(|&&(_, v)| v == value)
+            .map(|(name, _)| name.as_str())
+            .expect("value was validated against the enum definition on load")
+    }
+}
+
+impl ColumnData for Enum8ColumnData {
+    fn sql_type(&self) -> SqlType {
+        SqlType::Enum8
+    }
+
+    fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    fn at(&self, index: usize) -> ValueRef {
+        ValueRef::Enum(i16::from(self.value_at(index)), self.name_at(index))
+    }
+}
+
+/// Column data for `Enum16(...)`. See [`Enum8ColumnData`] for the format.
+pub struct Enum16ColumnData {
+    pub values: Vec<i16>,
+    pub value_map: Vec<(String, i16)>,
+}
+
+impl Enum16ColumnData {
+    pub fn load<T: ReadEx>(reader: &mut T, size: usize, enum_values: &str) -> Result<Self> {
+        let mut value_map = Vec::with_capacity(16);
+        for (name, value) in parse_enum_values(enum_values)? {
+            if value < i64::from(i16::min_value()) || value > i64::from(i16::max_value()) {
+                return Err(Error::FromSql(FromSqlError::OutOfRange));
+            }
+            value_map.push((name, value as i16));
+        }
+
+        let mut values = Vec::with_capacity(size);
+        for _ in 0..size {
+            values.push(reader.read_i16()?);
+        }
+
+        for &value in &values {
+            if !value_map.iter().any(|&(_, v)| v == value) {
+                return Err(Error::FromSql(FromSqlError::OutOfRange));
+            }
+        }
+
+        Ok(Enum16ColumnData { values, value_map })
+    }
+
+    /// The raw integer code stored for the row at `index`.
+    pub fn value_at(&self, index: usize) -> i16 {
+        self.values[index]
+    }
+
+    /// The string label the row at `index` resolves to.
+    pub fn name_at(&self, index: usize) -> &str {
+        let value = self.values[index];
+        self.value_map
+            .iter()
+            .find(|&&(_, v)| v == value)
+            .map(|(name, _)| name.as_str())
+            .expect("value was validated against the enum definition on load")
+    }
+}
+
+impl ColumnData for Enum16ColumnData {
+    fn sql_type(&self) -> SqlType {
+        SqlType::Enum16
+    }
+
+    fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    fn at(&self, index: usize) -> ValueRef {
+        ValueRef::Enum(self.value_at(index), self.name_at(index))
+    }
+}
+
+/// Parses the comma-separated `'name' = value` list out of an
+/// `Enum8(...)`/`Enum16(...)` type string. Tolerates quoted labels that
+/// contain `=`/`,` or an escaped quote (`''`), and arbitrary whitespace
+/// around the `=` separators. Values are returned widened to `i64` so callers can
+/// range-check them against their own width before narrowing.
+fn parse_enum_values(src: &str) -> Result<Vec<(String, i64)>> {
+    let mut chars = src.chars().peekable();
+    let mut values = Vec::new();
+
+    loop {
+        skip_while(&mut chars, |c| c.is_whitespace() || c == ',');
+
+        if chars.peek().is_none() {
+            break;
+        }
+
+        if chars.next() != Some('\'') {
+            return Err(invalid_definition_error(src));
+        }
+
+        let mut name = String::new();
+        loop {
+            match chars.next() {
+                Some('\'') => {
+                    if chars.peek() == Some(&'\'') {
+                        name.push('\'');
+                        chars.next();
+                    } else {
+                        break;
+                    }
+                }
+                Some(c) => name.push(c),
+                None => return Err(invalid_definition_error(src)),
+            }
+        }
+
+        skip_while(&mut chars, |c| c.is_whitespace());
+        if chars.next() != Some('=') {
+            return Err(invalid_definition_error(src));
+        }
+        skip_while(&mut chars, |c| c.is_whitespace());
+
+        let mut number = String::new();
+        if chars.peek() == Some(&'-') {
+            number.push('-');
+            chars.next();
+        }
+        while let Some(&c) = chars.peek() {
+            if c.is_ascii_digit() {
+                number.push(c);
+                chars.next();
+            } else {
+                break;
+            }
+        }
+
+        let value: i64 = number.parse().map_err(|_| invalid_definition_error(src))?;
+        values.push((name, value));
+    }
+
+    Ok(values)
+}
+
+fn skip_while<I: Iterator<Item = char>, F: Fn(char) -> bool>(
+    chars: &mut std::iter::Peekable<I>,
+    pred: F,
+) {
+    while let Some(&c) = chars.peek() {
+        if pred(c) {
+            chars.next();
+        } else {
+            break;
+        }
+    }
+}
+
+fn invalid_definition_error(src: &str) -> Error {
+    format!("Invalid Enum definition \"{}\".", src).into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse_enum_values;
+
+    #[test]
+    fn parses_simple_definition() {
+        let values = parse_enum_values("'a' = 1, 'b' = 2").unwrap();
+        assert_eq!(values, vec![("a".to_string(), 1), ("b".to_string(), 2)]);
+    }
+
+    #[test]
+    fn tolerates_whitespace_and_negative_values() {
+        let values = parse_enum_values(" 'a'=-1 , 'b' = 2 ").unwrap();
+        assert_eq!(values, vec![("a".to_string(), -1), ("b".to_string(), 2)]);
+    }
+
+    #[test]
+    fn supports_escaped_quotes_in_labels() {
+        let values = parse_enum_values("'it''s, me' = 1").unwrap();
+        assert_eq!(values, vec![("it's, me".to_string(), 1)]);
+    }
+
+    #[test]
+    fn rejects_malformed_definitions() {
+        assert!(parse_enum_values("'a' : 1").is_err());
+        assert!(parse_enum_values("'a' = ").is_err());
+        assert!(parse_enum_values("a = 1").is_err());
+    }
+}
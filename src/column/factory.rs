@@ -1,7 +1,10 @@
 use std::io;
 
+use column::array::ArrayColumnData;
 use column::column_data::ColumnData;
 use column::date::DateColumnData;
+use column::enums::{Enum16ColumnData, Enum8ColumnData};
+use column::nullable::NullableColumnData;
 use column::numeric::VectorColumnData;
 use column::string::StringColumnData;
 
@@ -31,9 +34,59 @@ impl ColumnData {
             "Date" => Arc::new(DateColumnData::<u16>::load(reader, size, tz)?),
             "DateTime" => Arc::new(DateColumnData::<u32>::load(reader, size, tz)?),
             _ => {
-                let message = format!("Unsupported column type \"{}\".", type_name);
-                return Err(io::Error::new(io::ErrorKind::Other, message));
+                if let Some(inner_type) = parse_wrapper_type(type_name, "Nullable") {
+                    Arc::new(NullableColumnData::load(reader, inner_type, size, tz)?)
+                } else if let Some(inner_type) = parse_wrapper_type(type_name, "Array") {
+                    Arc::new(ArrayColumnData::load(reader, inner_type, size, tz)?)
+                } else if let Some(enum_values) = parse_wrapper_type(type_name, "Enum8") {
+                    Arc::new(Enum8ColumnData::load(reader, size, enum_values)?)
+                } else if let Some(enum_values) = parse_wrapper_type(type_name, "Enum16") {
+                    Arc::new(Enum16ColumnData::load(reader, size, enum_values)?)
+                } else {
+                    let message = format!("Unsupported column type \"{}\".", type_name);
+                    return Err(io::Error::new(io::ErrorKind::Other, message));
+                }
             }
         })
     }
 }
+
+/// Strips a `Wrapper(...)` type name (e.g. `Nullable(Int32)`) down to the
+/// inner type name, or returns `None` if `type_name` isn't wrapped in
+/// `wrapper`.
+fn parse_wrapper_type<'a>(type_name: &'a str, wrapper: &str) -> Option<&'a str> {
+    if type_name.starts_with(wrapper)
+        && type_name.as_bytes().get(wrapper.len()) == Some(&b'(')
+        && type_name.ends_with(')')
+    {
+        Some(&type_name[wrapper.len() + 1..type_name.len() - 1])
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse_wrapper_type;
+
+    #[test]
+    fn parses_a_wrapped_type() {
+        assert_eq!(parse_wrapper_type("Nullable(Int32)", "Nullable"), Some("Int32"));
+        assert_eq!(parse_wrapper_type("Array(String)", "Array"), Some("String"));
+    }
+
+    #[test]
+    fn parses_nested_wrapped_types() {
+        assert_eq!(
+            parse_wrapper_type("Array(Nullable(Int32))", "Array"),
+            Some("Nullable(Int32)")
+        );
+    }
+
+    #[test]
+    fn rejects_other_wrappers_and_bare_types() {
+        assert_eq!(parse_wrapper_type("Array(Int32)", "Nullable"), None);
+        assert_eq!(parse_wrapper_type("Int32", "Nullable"), None);
+        assert_eq!(parse_wrapper_type("NullableFoo(Int32)", "Nullable"), None);
+    }
+}
@@ -0,0 +1,59 @@
+use std::io;
+use std::io::Read;
+use std::sync::Arc;
+
+use column::column_data::{ColumnData, SqlType, ValueRef};
+
+use binary::ReadEx;
+use chrono_tz::Tz;
+
+/// Column data for `Nullable(T)`.
+///
+/// The wire format is a contiguous null-mask of `size` bytes (`1` means the
+/// row is `NULL`, `0` means it is present) followed by the inner column's
+/// values, serialized exactly as a plain `T` column of the same `size`. The
+/// inner values are read through the generic `ColumnData::load_data` dispatch
+/// so nesting (e.g. `Array(Nullable(Int32))`) composes for free.
+pub struct NullableColumnData {
+    pub inner: Arc<ColumnData + Send + Sync>,
+    pub nulls: Vec<u8>,
+}
+
+impl NullableColumnData {
+    pub fn load<T: ReadEx>(
+        reader: &mut T,
+        inner_type: &str,
+        size: usize,
+        tz: Tz,
+    ) -> Result<Self, io::Error> {
+        let mut nulls = vec![0_u8; size];
+        reader.read_exact(&mut nulls)?;
+
+        let inner = ColumnData::load_data(reader, inner_type, size, tz)?;
+
+        Ok(NullableColumnData { inner, nulls })
+    }
+
+    /// Returns `true` if the value at `index` is `NULL`.
+    pub fn is_null(&self, index: usize) -> bool {
+        self.nulls[index] != 0
+    }
+}
+
+impl ColumnData for NullableColumnData {
+    fn sql_type(&self) -> SqlType {
+        SqlType::Nullable(Box::new(self.inner.sql_type()))
+    }
+
+    fn len(&self) -> usize {
+        self.nulls.len()
+    }
+
+    fn at(&self, index: usize) -> ValueRef {
+        if self.is_null(index) {
+            ValueRef::Null
+        } else {
+            self.inner.at(index)
+        }
+    }
+}
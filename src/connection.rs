@@ -0,0 +1,92 @@
+use std::net::{SocketAddr, ToSocketAddrs};
+
+use futures::Future;
+use tokio::net::TcpStream;
+use url::Url;
+
+use crate::errors::{Error, Result, UrlError};
+use crate::retry::{reconnect_with_backoff, ReconnectOptions};
+
+/// Connection parameters parsed out of a `tcp://host:port?param=value` URL.
+pub struct ConnectionOptions {
+    pub addr: SocketAddr,
+    pub reconnect: ReconnectOptions,
+}
+
+impl ConnectionOptions {
+    /// Parses a connection URL. Query parameters are first offered to
+    /// [`ReconnectOptions::apply_param`]; anything that isn't a reconnect
+    /// knob is rejected as an unknown parameter.
+    pub fn from_url(url: &str) -> Result<Self> {
+        let parsed = Url::parse(url)?;
+
+        if parsed.scheme() != "tcp" {
+            return Err(UrlError::UnsupportedScheme {
+                scheme: parsed.scheme().to_string(),
+            }
+            .into());
+        }
+
+        let host = parsed.host_str().ok_or(UrlError::Invalid)?;
+        let port = parsed.port().unwrap_or(9000);
+        let addr = (host, port)
+            .to_socket_addrs()
+            .map_err(|_| UrlError::Invalid)?
+            .next()
+            .ok_or(UrlError::Invalid)?;
+
+        let mut reconnect = ReconnectOptions::default();
+        for (param, value) in parsed.query_pairs() {
+            if !reconnect.apply_param(&param, &value)? {
+                return Err(UrlError::UnknownParameter {
+                    param: param.to_string(),
+                }
+                .into());
+            }
+        }
+
+        Ok(ConnectionOptions { addr, reconnect })
+    }
+}
+
+/// Opens a TCP connection per `options`, retrying transient failures with
+/// exponential backoff per `options.reconnect`.
+pub fn connect(options: &ConnectionOptions) -> impl Future<Item = TcpStream, Error = Error> {
+    let addr = options.addr;
+    reconnect_with_backoff(options.reconnect, move || {
+        TcpStream::connect(&addr).map_err(Error::from)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_host_port_and_reconnect_params() {
+        let options =
+            ConnectionOptions::from_url("tcp://127.0.0.1:9001?retry_base=200").unwrap();
+
+        assert_eq!(options.addr, "127.0.0.1:9001".parse().unwrap());
+        assert_eq!(
+            options.reconnect.base_interval,
+            std::time::Duration::from_millis(200)
+        );
+    }
+
+    #[test]
+    fn defaults_to_the_native_port() {
+        let options = ConnectionOptions::from_url("tcp://127.0.0.1").unwrap();
+        assert_eq!(options.addr, "127.0.0.1:9000".parse().unwrap());
+    }
+
+    #[test]
+    fn rejects_unsupported_schemes() {
+        assert!(ConnectionOptions::from_url("http://127.0.0.1").is_err());
+    }
+
+    #[test]
+    fn rejects_unknown_query_parameters() {
+        assert!(ConnectionOptions::from_url("tcp://127.0.0.1?bogus=1").is_err());
+    }
+}
@@ -1,6 +1,9 @@
-use std::{borrow::Cow, io, mem, result, str::Utf8Error, string::FromUtf8Error};
+use std::{
+    borrow::Cow, error::Error as StdError, fmt, io, mem, result, str::Utf8Error,
+    string::FromUtf8Error,
+};
 
-use failure::*;
+use thiserror::Error;
 use tokio::prelude::*;
 use tokio_timer::timeout::Error as TimeoutError;
 use tokio_timer::Error as TimerError;
@@ -12,25 +15,33 @@ use crate::types::Packet;
 pub type Result<T> = result::Result<T, Error>;
 
 /// This type enumerates library errors.
-#[derive(Debug)]
+#[derive(Debug, Error)]
 pub enum Error {
-    Driver(DriverError),
+    #[error(transparent)]
+    Driver(#[from] DriverError),
 
-    Io(io::Error),
+    #[error(transparent)]
+    Io(#[from] io::Error),
 
-    Connection(ConnectionError),
+    #[error(transparent)]
+    Connection(#[from] ConnectionError),
 
-    Other(failure::Error),
+    #[error("{0}")]
+    Other(#[source] Box<dyn std::error::Error + Send + Sync>),
 
-    Server(ServerError),
+    #[error(transparent)]
+    Server(#[from] ServerError),
 
-    Url(UrlError),
+    #[error(transparent)]
+    Url(#[from] UrlError),
 
-    FromSql(FromSqlError),
+    #[error(transparent)]
+    FromSql(#[from] FromSqlError),
 }
 
 /// This type represents Clickhouse server error.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Error)]
+#[error("Received error from server: code: {code}, name: {name}, message: {message}")]
 pub struct ServerError {
     pub code: u32,
     pub name: String,
@@ -39,102 +50,100 @@ pub struct ServerError {
 }
 
 /// This type enumerates connection errors.
-#[derive(Debug)]
+#[derive(Debug, Error)]
 pub enum ConnectionError {
+    #[error("TLS connection requires a host name to be provided")]
     TlsHostNotProvided,
 
-    IoError(io::Error),
+    #[error("{0}")]
+    IoError(#[source] io::Error),
 
     #[cfg(feature = "tls")]
-    TlsError(native_tls::Error),
+    #[error("{0}")]
+    TlsError(#[source] native_tls::Error),
 }
 
 /// This type enumerates connection URL errors.
-#[derive(Debug)]
+#[derive(Debug, Error)]
 pub enum UrlError {
+    #[error("Invalid or incomplete connection URL")]
     Invalid,
 
+    #[error("Invalid value \"{value}\" for connection URL parameter \"{param}\"")]
     InvalidParamValue { param: String, value: String },
 
-    Parse(ParseError),
+    #[error("{0}")]
+    Parse(#[source] ParseError),
 
+    #[error("Unknown connection URL parameter \"{param}\"")]
     UnknownParameter { param: String },
 
+    #[error("Unsupported connection URL scheme \"{scheme}\"")]
     UnsupportedScheme { scheme: String },
 }
 
 /// This type enumerates driver errors.
-#[derive(Debug)]
+#[derive(Debug, Error)]
 pub enum DriverError {
+    #[error("Overflow")]
     Overflow,
 
+    #[error("Unknown packet {packet}")]
     UnknownPacket { packet: u64 },
 
+    #[error("Unexpected packet")]
     UnexpectedPacket,
 
+    #[error("Timeout")]
     Timeout,
 
-    Utf8Error(Utf8Error),
+    #[error("{0}")]
+    Utf8Error(#[source] Utf8Error),
 }
 
 /// This type enumerates cast from sql type errors.
-#[derive(Debug)]
+#[derive(Debug, Error)]
 pub enum FromSqlError {
+    #[error("SQL type mismatch: {src} cannot be cast to {dst}")]
     InvalidType {
         src: Cow<'static, str>,
         dst: Cow<'static, str>,
     },
 
+    #[error("Value is out of range")]
     OutOfRange,
 
+    #[error("Unsupported operation")]
     UnsupportedOperation,
 }
 
-impl From<DriverError> for Error {
-    fn from(err: DriverError) -> Self {
-        Error::Driver(err)
-    }
-}
-
-impl From<io::Error> for Error {
-    fn from(err: io::Error) -> Self {
-        Error::Io(err)
-    }
-}
-
-impl From<ServerError> for Error {
-    fn from(err: ServerError) -> Self {
-        Error::Server(err)
-    }
-}
-
-impl From<UrlError> for Error {
-    fn from(err: UrlError) -> Self {
-        Error::Url(err)
-    }
-}
+/// A leaf error used to carry a plain message in [`Error::Other`] without
+/// depending on an external "anyhow-style" error crate.
+#[derive(Debug, Error)]
+#[error("{0}")]
+struct OtherError(String);
 
 impl From<String> for Error {
     fn from(err: String) -> Self {
-        Error::Other(failure::Context::new(err).into())
+        Error::Other(Box::new(OtherError(err)))
     }
 }
 
 impl From<&str> for Error {
     fn from(err: &str) -> Self {
-        Error::Other(failure::Context::new(err.to_string()).into())
+        Error::Other(Box::new(OtherError(err.to_string())))
     }
 }
 
 impl From<FromUtf8Error> for Error {
     fn from(err: FromUtf8Error) -> Self {
-        Error::Other(failure::Context::new(err).into())
+        Error::Other(Box::new(err))
     }
 }
 
 impl From<TimerError> for Error {
     fn from(err: TimerError) -> Self {
-        Error::Other(failure::Context::new(err).into())
+        Error::Other(Box::new(err))
     }
 }
 
@@ -159,9 +168,93 @@ impl From<Utf8Error> for Error {
     }
 }
 
-impl From<ConnectionError> for Error {
-    fn from(err: ConnectionError) -> Self {
-        Error::Connection(err)
+impl Error {
+    /// Returns a wrapper that prints this error together with its full
+    /// cause chain, one link per line. For `Error::Server` it additionally
+    /// surfaces the server-side `stack_trace`, which is otherwise captured
+    /// but never formatted (`code`/`name` are already part of its `Display`
+    /// message).
+    pub fn display_chain(&self) -> ErrorChainDisplay<'_> {
+        ErrorChainDisplay(self)
+    }
+
+    /// Returns `true` if the error is likely transient, i.e. a retry of the
+    /// operation that produced it has a reasonable chance of succeeding.
+    ///
+    /// This is used to drive the connection layer's automatic reconnect: a
+    /// transient error (dropped socket, timeout) is retried with backoff,
+    /// while a permanent one (bad credentials, malformed URL, a server-side
+    /// `Error::Server`) is propagated immediately.
+    pub fn is_transient(&self) -> bool {
+        match self {
+            Error::Io(e) => is_transient_io_error(e),
+            Error::Connection(e) => e.is_transient(),
+            Error::Driver(DriverError::Timeout) => true,
+            _ => false,
+        }
+    }
+}
+
+impl ConnectionError {
+    /// See [`Error::is_transient`].
+    pub fn is_transient(&self) -> bool {
+        match self {
+            ConnectionError::IoError(e) => is_transient_io_error(e),
+            ConnectionError::TlsHostNotProvided => false,
+            #[cfg(feature = "tls")]
+            ConnectionError::TlsError(_) => false,
+        }
+    }
+}
+
+fn is_transient_io_error(err: &io::Error) -> bool {
+    match err.kind() {
+        io::ErrorKind::ConnectionRefused
+        | io::ErrorKind::ConnectionReset
+        | io::ErrorKind::ConnectionAborted
+        | io::ErrorKind::BrokenPipe
+        | io::ErrorKind::TimedOut => true,
+        _ => false,
+    }
+}
+
+/// Displays an [`Error`] along with its full `source()` chain.
+///
+/// Obtained via [`Error::display_chain`].
+pub struct ErrorChainDisplay<'a>(&'a Error);
+
+impl<'a> fmt::Display for ErrorChainDisplay<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let top = self.0.to_string();
+        write!(f, "{}", top)?;
+
+        if let Error::Server(ref server_error) = self.0 {
+            if !server_error.stack_trace.is_empty() {
+                write!(f, "\n  stack trace:")?;
+                for line in server_error.stack_trace.lines() {
+                    write!(f, "\n    {}", line)?;
+                }
+            }
+        }
+
+        // A `#[error(transparent)]` variant forwards both its `Display` and
+        // its `source()` straight through to the value it wraps, so the
+        // first link in the chain is often the exact same error we just
+        // rendered above (e.g. `Error::Connection(ConnectionError::IoError(e))`
+        // prints `e`, then yields `e` again as its own source). Skip a link
+        // whose rendered message is identical to the one before it.
+        let mut last_rendered = top;
+        let mut source = StdError::source(self.0);
+        while let Some(err) = source {
+            let rendered = err.to_string();
+            if rendered != last_rendered {
+                write!(f, "\ncaused by: {}", rendered)?;
+            }
+            last_rendered = rendered;
+            source = err.source();
+        }
+
+        Ok(())
     }
 }
 
@@ -178,7 +271,7 @@ impl<S> Into<Poll<Option<Packet<S>>, Error>> for Error {
             return Err(Error::Io(me));
         }
 
-        warn!("ERROR: {:?}", this);
+        warn!("ERROR: {}", this.display_chain());
         Err(this)
     }
 }
@@ -191,3 +284,51 @@ impl From<Error> for io::Error {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn display_chain_does_not_repeat_the_server_error_message() {
+        let err = Error::Server(ServerError {
+            code: 42,
+            name: "SOME_ERROR".into(),
+            message: "something went wrong".into(),
+            stack_trace: "frame 1\nframe 2".into(),
+        });
+
+        let rendered = err.display_chain().to_string();
+
+        assert_eq!(rendered.matches("code: 42").count(), 1);
+        assert_eq!(rendered.matches("SOME_ERROR").count(), 1);
+        assert!(rendered.contains("stack trace:"));
+        assert!(rendered.contains("frame 1"));
+        assert!(rendered.contains("frame 2"));
+    }
+
+    #[test]
+    fn display_chain_omits_stack_trace_section_when_empty() {
+        let err = Error::Server(ServerError {
+            code: 1,
+            name: "X".into(),
+            message: "m".into(),
+            stack_trace: String::new(),
+        });
+
+        assert!(!err.display_chain().to_string().contains("stack trace"));
+    }
+
+    #[test]
+    fn display_chain_does_not_repeat_a_transparent_wrapper_source() {
+        let io_err = io::Error::new(io::ErrorKind::ConnectionRefused, "refused");
+        let message = io_err.to_string();
+        let err = Error::Connection(ConnectionError::IoError(io_err));
+
+        let rendered = err.display_chain().to_string();
+
+        assert_eq!(rendered, message);
+        assert_eq!(rendered.matches(&message).count(), 1);
+        assert!(!rendered.contains("caused by"));
+    }
+}
@@ -0,0 +1,169 @@
+use std::time::{Duration, Instant};
+
+use futures::future::{loop_fn, Loop};
+use futures::Future;
+use tokio_timer::Delay;
+
+use crate::errors::{Error, Result, UrlError};
+
+/// Exponential-backoff parameters for the connection layer's reconnect loop.
+#[derive(Debug, Clone, Copy)]
+pub struct ReconnectOptions {
+    /// Delay before the first retry attempt.
+    pub base_interval: Duration,
+
+    /// Factor the delay is multiplied by after each failed attempt.
+    pub multiplier: f64,
+
+    /// Upper bound on the per-attempt delay.
+    pub max_interval: Duration,
+
+    /// Stop retrying once this much time has elapsed since the first
+    /// attempt. `None` means retry forever.
+    pub max_elapsed_time: Option<Duration>,
+}
+
+impl Default for ReconnectOptions {
+    fn default() -> Self {
+        Self {
+            base_interval: Duration::from_millis(500),
+            multiplier: 1.5,
+            max_interval: Duration::from_secs(30),
+            max_elapsed_time: Some(Duration::from_secs(15 * 60)),
+        }
+    }
+}
+
+impl ReconnectOptions {
+    fn delay_for(&self, attempt: u32) -> Duration {
+        let scaled =
+            self.base_interval.as_millis() as f64 * self.multiplier.powi(attempt as i32);
+        let capped = scaled.min(self.max_interval.as_millis() as f64);
+        Duration::from_millis(capped as u64)
+    }
+
+    /// Applies a single connection-URL query parameter to `self`. Returns
+    /// `true` if `param` was one of the reconnect knobs (`retry_base`,
+    /// `retry_multiplier`, `retry_max_interval`, `retry_max_elapsed_time`,
+    /// each a millisecond count except the unitless `retry_multiplier`) and
+    /// was applied, or `false` if `param` isn't a reconnect knob, so the
+    /// caller can go on to try matching it against its own parameters.
+    pub(crate) fn apply_param(&mut self, param: &str, value: &str) -> Result<bool> {
+        match param {
+            "retry_base" => self.base_interval = parse_millis(param, value)?,
+            "retry_multiplier" => self.multiplier = parse_f64(param, value)?,
+            "retry_max_interval" => self.max_interval = parse_millis(param, value)?,
+            "retry_max_elapsed_time" => self.max_elapsed_time = Some(parse_millis(param, value)?),
+            _ => return Ok(false),
+        }
+        Ok(true)
+    }
+}
+
+fn parse_millis(param: &str, value: &str) -> Result<Duration> {
+    value
+        .parse::<u64>()
+        .map(Duration::from_millis)
+        .map_err(|_| invalid_param(param, value))
+}
+
+fn parse_f64(param: &str, value: &str) -> Result<f64> {
+    value.parse::<f64>().map_err(|_| invalid_param(param, value))
+}
+
+fn invalid_param(param: &str, value: &str) -> Error {
+    UrlError::InvalidParamValue {
+        param: param.to_string(),
+        value: value.to_string(),
+    }
+    .into()
+}
+
+/// Retries `connect` with exponential backoff while its error is
+/// [`Error::is_transient`], and propagates any other error immediately.
+pub(crate) fn reconnect_with_backoff<C, F>(
+    options: ReconnectOptions,
+    mut connect: C,
+) -> impl Future<Item = F::Item, Error = Error>
+where
+    C: FnMut() -> F,
+    F: Future<Error = Error>,
+{
+    let started_at = Instant::now();
+
+    loop_fn(0u32, move |attempt| {
+        connect().then(move |result| -> Box<dyn Future<Item = Loop<F::Item, u32>, Error = Error>> {
+            match result {
+                Ok(item) => Box::new(futures::future::ok(Loop::Break(item))),
+                Err(err) => {
+                    let elapsed_too_long = options
+                        .max_elapsed_time
+                        .map_or(false, |max| started_at.elapsed() >= max);
+
+                    if !err.is_transient() || elapsed_too_long {
+                        return Box::new(futures::future::err(err));
+                    }
+
+                    let delay = options.delay_for(attempt);
+                    Box::new(
+                        Delay::new(Instant::now() + delay)
+                            .map_err(Error::from)
+                            .and_then(move |_| Ok(Loop::Continue(attempt + 1))),
+                    )
+                }
+            }
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn delay_grows_exponentially_up_to_the_cap() {
+        let options = ReconnectOptions {
+            base_interval: Duration::from_millis(100),
+            multiplier: 2.0,
+            max_interval: Duration::from_millis(350),
+            max_elapsed_time: None,
+        };
+
+        assert_eq!(options.delay_for(0), Duration::from_millis(100));
+        assert_eq!(options.delay_for(1), Duration::from_millis(200));
+        assert_eq!(options.delay_for(2), Duration::from_millis(350));
+        assert_eq!(options.delay_for(3), Duration::from_millis(350));
+    }
+
+    #[test]
+    fn apply_param_parses_known_reconnect_knobs() {
+        let mut options = ReconnectOptions::default();
+
+        assert_eq!(options.apply_param("retry_base", "1000").unwrap(), true);
+        assert_eq!(options.base_interval, Duration::from_millis(1000));
+
+        assert_eq!(
+            options.apply_param("retry_multiplier", "2.5").unwrap(),
+            true
+        );
+        assert_eq!(options.multiplier, 2.5);
+
+        assert_eq!(
+            options.apply_param("retry_max_elapsed_time", "60000").unwrap(),
+            true
+        );
+        assert_eq!(options.max_elapsed_time, Some(Duration::from_millis(60000)));
+    }
+
+    #[test]
+    fn apply_param_ignores_unrelated_params() {
+        let mut options = ReconnectOptions::default();
+        assert_eq!(options.apply_param("compress", "true").unwrap(), false);
+    }
+
+    #[test]
+    fn apply_param_rejects_invalid_values() {
+        let mut options = ReconnectOptions::default();
+        assert!(options.apply_param("retry_base", "not-a-number").is_err());
+    }
+}